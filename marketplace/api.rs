@@ -0,0 +1,59 @@
+//! Interfaz pública del marketplace para llamadas entre contratos.
+//!
+//! Otros contratos (agregadores, administradores de escrow) pueden invocar el flujo de
+//! compra con llamadas tipadas en lugar de armar los selectores a mano:
+//!
+//! ```ignore
+//! let mut marketplace: ink::contract_ref!(MarketplaceApi) = direccion.into();
+//! let orden = marketplace.marcar_recibido(idx_orden)?;
+//! ```
+//!
+//! Los selectores se fijan explícitamente al nombre del mensaje, sin el prefijo del trait,
+//! para que las integraciones existentes que llaman por selector sigan funcionando.
+
+use crate::marketplace::{Categoria, ErrorSistema, OrdenCompra, Publicacion, Rol, Usuario};
+use ink::prelude::string::String;
+use ink::prelude::vec::Vec;
+
+/// Mensajes del flujo de compra expuestos a otros contratos.
+#[ink::trait_definition]
+pub trait MarketplaceApi {
+    /// Registra a quien llama como usuario con el rol indicado.
+    #[ink(message, selector = 0x8C9B70D4)]
+    fn registrar_usuario(&mut self, username: String, rol: Rol) -> Result<Usuario, ErrorSistema>;
+
+    /// Retorna los datos del usuario que llama.
+    #[ink(message, selector = 0xDAFB95C8)]
+    fn get_usuario(&self) -> Result<Usuario, ErrorSistema>;
+
+    /// Publica un producto a nombre de quien llama, que debe ser vendedor.
+    #[ink(message, selector = 0xAF3CDFE3)]
+    fn publicar(
+        &mut self,
+        nombre: String,
+        descripcion: String,
+        precio: u64,
+        categoria: Categoria,
+        stock: u64,
+    ) -> Result<Publicacion, ErrorSistema>;
+
+    /// Retorna todas las publicaciones existentes.
+    #[ink(message, selector = 0xAF58694A)]
+    fn get_publicaciones(&self) -> Vec<Publicacion>;
+
+    /// Crea una orden de compra pagada con el valor transferido, que queda retenido en escrow.
+    #[ink(message, payable, selector = 0x8D63841F)]
+    fn ordenar_compra(&mut self, idx_publicacion: u32, cantidad: u32) -> Result<OrdenCompra, ErrorSistema>;
+
+    /// Marca una orden como enviada. Solo puede llamarlo el vendedor.
+    #[ink(message, selector = 0x981AF00F)]
+    fn marcar_enviado(&mut self, idx_orden: u32) -> Result<OrdenCompra, ErrorSistema>;
+
+    /// Marca una orden como recibida y libera el pago al vendedor. Solo puede llamarlo el comprador.
+    #[ink(message, selector = 0xEEFAE3AB)]
+    fn marcar_recibido(&mut self, idx_orden: u32) -> Result<OrdenCompra, ErrorSistema>;
+
+    /// Solicita o aprueba la cancelación de una orden.
+    #[ink(message, selector = 0xB0CB1E44)]
+    fn cancelar_orden(&mut self, idx_orden: u32) -> Result<OrdenCompra, ErrorSistema>;
+}
@@ -0,0 +1,129 @@
+//! Aritmética de precios compartida por comisiones, descuentos, primas, donaciones y cuotas.
+//!
+//! Todas las operaciones con porcentajes pasan por este módulo para que el redondeo sea
+//! explícito y los repartos cierren exactamente: cuando un monto se separa en partes, la suma
+//! de las partes es siempre igual al monto original, y el resto de la división se asigna a
+//! una parte determinada en lugar de perderse.
+//!
+//! Reglas usadas por el contrato:
+//! - Comisiones, primas, penalizaciones y donaciones porcentuales: `Redondeo::Abajo`, a favor
+//!   de quien paga. El neto se obtiene como resto, de modo que `parte + neto == monto`.
+//! - Cuotas: cada cuota es el total dividido la cantidad de cuotas redondeado hacia abajo, y la
+//!   última absorbe el resto.
+
+/// Denominador de los porcentajes expresados en puntos básicos.
+pub const BPS: u128 = 10_000;
+
+/// Regla de redondeo de una división.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Redondeo {
+    /// Hacia abajo (truncamiento).
+    Abajo,
+
+    /// Hacia arriba.
+    Arriba,
+
+    /// Al más cercano; los empates van al par (redondeo bancario).
+    Bancario,
+}
+
+/// Calcula `valor * numerador / denominador` con la regla de redondeo indicada, sin
+/// desbordar mientras `numerador * denominador` entre en un `u128`.
+///
+/// Un denominador cero retorna cero.
+pub fn multiplicar_dividir(valor: u128, numerador: u128, denominador: u128, redondeo: Redondeo) -> u128 {
+    if denominador == 0 {
+        return 0;
+    }
+
+    // valor = cociente * denominador + resto, con resto < denominador
+    let cociente = valor / denominador;
+    let resto = valor % denominador;
+    let producto_resto = resto.saturating_mul(numerador);
+    let base = cociente
+        .saturating_mul(numerador)
+        .saturating_add(producto_resto / denominador);
+    let sobrante = producto_resto % denominador;
+
+    let sube = match redondeo {
+        Redondeo::Abajo => false,
+        Redondeo::Arriba => sobrante > 0,
+        Redondeo::Bancario => {
+            let doble = sobrante.saturating_mul(2);
+            doble > denominador || (doble == denominador && base % 2 == 1)
+        }
+    };
+    if sube {
+        base.saturating_add(1)
+    } else {
+        base
+    }
+}
+
+/// Calcula el porcentaje `bps` (en puntos básicos) de un monto.
+pub fn porcentaje(monto: u128, bps: u16, redondeo: Redondeo) -> u128 {
+    multiplicar_dividir(monto, bps as u128, BPS, redondeo)
+}
+
+/// Calcula el porcentaje `bps` de un monto con un descuento `descuento_bps` sobre ese porcentaje,
+/// redondeando una sola vez al final.
+pub fn porcentaje_con_descuento(monto: u128, bps: u16, descuento_bps: u16, redondeo: Redondeo) -> u128 {
+    let efectivo = (bps as u128).saturating_mul(BPS.saturating_sub(descuento_bps as u128));
+    multiplicar_dividir(monto, efectivo, BPS * BPS, redondeo)
+}
+
+/// Separa un monto en `(parte, resto)`, donde `parte` es el porcentaje `bps` del monto.
+///
+/// La suma de ambas partes es siempre igual al monto.
+pub fn separar(monto: u128, bps: u16, redondeo: Redondeo) -> (u128, u128) {
+    let parte = porcentaje(monto, bps, redondeo).min(monto);
+    (parte, monto - parte)
+}
+
+/// Retorna el monto de la cuota `numero` (empezando en 1) de un total dividido en `cuotas`.
+///
+/// Las cuotas son iguales, redondeadas hacia abajo, y la última absorbe el resto, de modo que
+/// la suma de todas es exactamente el total.
+pub fn cuota(total: u128, cuotas: u8, numero: u8) -> u128 {
+    if cuotas == 0 {
+        return 0;
+    }
+    let base = total / cuotas as u128;
+    if numero >= cuotas {
+        total - base * (cuotas as u128 - 1)
+    } else {
+        base
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verifica las tres reglas de redondeo, incluidos los empates del redondeo bancario.
+    #[test]
+    fn tests_redondeo() {
+        assert_eq!(multiplicar_dividir(7, 1, 2, Redondeo::Abajo), 3);
+        assert_eq!(multiplicar_dividir(7, 1, 2, Redondeo::Arriba), 4);
+        assert_eq!(multiplicar_dividir(7, 1, 2, Redondeo::Bancario), 4);
+        assert_eq!(multiplicar_dividir(5, 1, 2, Redondeo::Bancario), 2);
+        assert_eq!(multiplicar_dividir(8, 1, 3, Redondeo::Bancario), 3);
+        assert_eq!(multiplicar_dividir(6, 1, 2, Redondeo::Arriba), 3);
+        assert_eq!(multiplicar_dividir(u128::MAX, BPS, BPS, Redondeo::Abajo), u128::MAX);
+        assert_eq!(multiplicar_dividir(10, 1, 0, Redondeo::Arriba), 0);
+    }
+
+    /// Verifica que los repartos cierren exactamente con el monto original.
+    #[test]
+    fn tests_repartos_cierran() {
+        for monto in [0u128, 1, 99, 1_001, 123_457] {
+            let (parte, resto) = separar(monto, 333, Redondeo::Arriba);
+            assert_eq!(parte + resto, monto);
+
+            let suma: u128 = (1..=7).map(|numero| cuota(monto, 7, numero)).sum();
+            assert_eq!(suma, monto);
+        }
+        assert_eq!(porcentaje_con_descuento(1_000_000, 500, 2_000, Redondeo::Abajo), 40_000);
+        assert_eq!(separar(100, 10_000, Redondeo::Abajo), (100, 0));
+    }
+}
@@ -0,0 +1,116 @@
+//! Textos de longitud acotada usados por usuarios y publicaciones.
+//!
+//! Cada tipo envuelve un `String` y garantiza que su longitud en bytes no supere el máximo del
+//! tipo: solo se construye con `new`, que rechaza los textos demasiado largos, y su decodificación
+//! SCALE falla ante un texto fuera de rango. Así un texto sobredimensionado no llega al storage ni
+//! por los mensajes ni por datos importados de otro contrato, y el depósito de storage de cada
+//! usuario y publicación queda acotado.
+//!
+//! La codificación es la misma que la de un `String`, por lo que los datos ya almacenados se leen
+//! sin migración.
+
+use ink::prelude::string::String;
+
+/// Longitud máxima, en bytes, del nombre de un producto.
+pub const MAX_LONGITUD_NOMBRE: usize = 64;
+
+/// Longitud máxima, en bytes, de la descripción de un producto.
+pub const MAX_LONGITUD_DESCRIPCION: usize = 1_024;
+
+/// Longitud máxima, en bytes, de un nombre de usuario.
+pub const MAX_LONGITUD_USERNAME: usize = 32;
+
+macro_rules! texto_acotado {
+    ($(#[$doc:meta])* $tipo:ident, $maximo:expr) => {
+        $(#[$doc])*
+        #[ink::scale_derive(Encode, TypeInfo)]
+        #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+        #[derive(Debug, Clone, PartialEq, Eq, Default)]
+        pub struct $tipo(String);
+
+        impl $tipo {
+            /// Longitud máxima en bytes.
+            pub const MAX: usize = $maximo;
+
+            /// Crea el texto, o retorna `None` si supera la longitud máxima.
+            pub fn new(texto: String) -> Option<Self> {
+                (texto.len() <= Self::MAX).then_some(Self(texto))
+            }
+
+            /// Retorna el texto.
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl ink::scale::Decode for $tipo {
+            fn decode<I: ink::scale::Input>(input: &mut I) -> Result<Self, ink::scale::Error> {
+                let texto = <String as ink::scale::Decode>::decode(input)?;
+                Self::new(texto).ok_or_else(|| "texto excede la longitud maxima".into())
+            }
+        }
+
+        impl From<$tipo> for String {
+            fn from(texto: $tipo) -> String {
+                texto.0
+            }
+        }
+
+        impl core::fmt::Display for $tipo {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl PartialEq<&str> for $tipo {
+            fn eq(&self, otro: &&str) -> bool {
+                self.0 == *otro
+            }
+        }
+    };
+}
+
+texto_acotado!(
+    /// Nombre de un producto, de hasta `MAX_LONGITUD_NOMBRE` bytes.
+    Nombre,
+    MAX_LONGITUD_NOMBRE
+);
+
+texto_acotado!(
+    /// Descripción de un producto, de hasta `MAX_LONGITUD_DESCRIPCION` bytes.
+    Descripcion,
+    MAX_LONGITUD_DESCRIPCION
+);
+
+texto_acotado!(
+    /// Nombre de usuario, de hasta `MAX_LONGITUD_USERNAME` bytes.
+    Username,
+    MAX_LONGITUD_USERNAME
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ink::scale::{Decode, Encode};
+
+    /// Verifica que solo se construyan textos dentro del máximo.
+    #[test]
+    fn tests_longitud_maxima() {
+        assert!(Username::new("a".repeat(MAX_LONGITUD_USERNAME)).is_some());
+        assert_eq!(Username::new("a".repeat(MAX_LONGITUD_USERNAME + 1)), None);
+        assert_eq!(Nombre::new("Silla".into()).map(String::from), Some("Silla".into()));
+        assert_eq!(Descripcion::default().as_str(), "");
+    }
+
+    /// Verifica que la codificación sea la de un `String` y que la decodificación rechace textos largos.
+    #[test]
+    fn tests_decodificacion_acotada() {
+        let username = Username::new("agustin22".into()).unwrap();
+        assert_eq!(username.encode(), String::from("agustin22").encode());
+        assert_eq!(Username::decode(&mut &username.encode()[..]), Ok(username));
+
+        let largo = "a".repeat(MAX_LONGITUD_USERNAME + 1).encode();
+        assert!(Username::decode(&mut &largo[..]).is_err());
+        assert!(Nombre::decode(&mut &largo[..]).is_ok());
+    }
+}
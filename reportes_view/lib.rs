@@ -30,13 +30,13 @@ mod reportes_view {
         /// A message that can be called on instantiated contracts.
         /// This one flips the value of the stored `bool` from `true`
         /// to `false` and vice versa.
-        #[ink(message)]
+        #[ink(message, selector = 0x633AA551)]
         pub fn flip(&mut self) {
             self.value = !self.value;
         }
 
         /// Simply returns the current value of our `bool`.
-        #[ink(message)]
+        #[ink(message, selector = 0x2F865BD9)]
         pub fn get(&self) -> bool {
             self.value
         }
@@ -0,0 +1,260 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+pub use self::tienda::{Categoria, ErrorTienda, ProductoTienda, Tienda, TiendaRef};
+
+#[ink::contract]
+pub mod tienda {
+    use ink::prelude::string::String;
+    use ink::prelude::vec::Vec;
+
+    /// Contrato hijo que administra el catálogo de un único vendedor.
+    ///
+    /// Es instanciado por el marketplace, que actúa como registro de tiendas y como
+    /// intermediario de las compras.
+    #[ink(storage)]
+    pub struct Tienda {
+        /// vendedor dueño de la tienda
+        propietario: AccountId,
+        /// contrato marketplace que instanció la tienda
+        marketplace: AccountId,
+        /// storage del catalogo de la tienda
+        productos: Vec<ProductoTienda>,
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, PartialEq)]
+    /// Define los posibles errores que pueden ocurrir en la tienda.
+    pub enum ErrorTienda {
+        /// El caller no es el vendedor dueño de la tienda.
+        NoEresPropietario,
+
+        /// El caller no es el marketplace que instanció la tienda.
+        NoEresMarketplace,
+
+        /// El producto solicitado no existe.
+        ProductoNoExistente,
+
+        /// El producto no posee stock suficiente.
+        ProductoSinStock,
+
+        /// Error por desbordamiento al manipular el stock.
+        OverflowStock,
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq)]
+    /// Categorías de producto, con la misma codificación que las del marketplace.
+    pub enum Categoria {
+        /// Productos relacionados con equipos y componentes de computación.
+        Computacion,
+
+        /// Prendas de vestir y accesorios.
+        Ropa,
+
+        /// Herramientas manuales o eléctricas.
+        Herramientas,
+
+        /// Muebles y artículos para el hogar.
+        Muebles,
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq)]
+    /// Representa un producto del catálogo de la tienda.
+    pub struct ProductoTienda {
+        /// Identificador del producto dentro de la tienda.
+        pub id_producto: u64,
+
+        /// Nombre del producto.
+        pub nombre: String,
+
+        /// Descripción del producto.
+        pub descripcion: String,
+
+        /// Categoría a la que pertenece el producto.
+        pub categoria: Categoria,
+
+        /// Precio del producto en la unidad base del token.
+        pub precio: u64,
+
+        /// Cantidad disponible en stock.
+        pub stock: u64,
+    }
+
+    impl Tienda {
+        /// Constructor del contrato `Tienda`.
+        ///
+        /// # Parámetros
+        /// - `propietario`: Vendedor dueño de la tienda.
+        /// - `marketplace`: Contrato marketplace autorizado a reservar stock.
+        #[ink(constructor)]
+        pub fn new(propietario: AccountId, marketplace: AccountId) -> Self {
+            Self {
+                propietario,
+                marketplace,
+                productos: Default::default(),
+            }
+        }
+
+        /// Agrega un producto al catálogo. Solo el propietario puede realizar esta acción.
+        ///
+        /// # Retorna
+        /// - `Ok(ProductoTienda)` con el producto creado.
+        /// - `Err(ErrorTienda::NoEresPropietario)` si el caller no es el propietario.
+        #[ink(message, selector = 0x53E63168)]
+        pub fn agregar_producto(
+            &mut self,
+            nombre: String,
+            descripcion: String,
+            categoria: Categoria,
+            precio: u64,
+            stock: u64,
+        ) -> Result<ProductoTienda, ErrorTienda> {
+            self._agregar_producto(self.env().caller(), nombre, descripcion, categoria, precio, stock)
+        }
+
+        /// Método interno que agrega un producto al catálogo.
+        fn _agregar_producto(
+            &mut self,
+            caller: AccountId,
+            nombre: String,
+            descripcion: String,
+            categoria: Categoria,
+            precio: u64,
+            stock: u64,
+        ) -> Result<ProductoTienda, ErrorTienda> {
+            if caller != self.propietario {
+                return Err(ErrorTienda::NoEresPropietario);
+            }
+
+            let producto = ProductoTienda {
+                id_producto: self.productos.len() as u64,
+                nombre,
+                descripcion,
+                categoria,
+                precio,
+                stock,
+            };
+            self.productos.push(producto.clone());
+            Ok(producto)
+        }
+
+        /// Retorna el catálogo completo de la tienda.
+        #[ink(message, selector = 0x05CBCE90)]
+        pub fn get_productos(&self) -> Vec<ProductoTienda> {
+            self.productos.clone()
+        }
+
+        /// Retorna el vendedor dueño de la tienda.
+        #[ink(message, selector = 0xDD423FA9)]
+        pub fn get_propietario(&self) -> AccountId {
+            self.propietario
+        }
+
+        /// Reserva stock de un producto para una orden de compra.
+        ///
+        /// Solo el marketplace puede realizar esta acción.
+        ///
+        /// # Retorna
+        /// - `Ok(ProductoTienda)` con el producto y su stock actualizado.
+        /// - `Err(ErrorTienda)` si el caller no es el marketplace, el producto no existe o no hay stock.
+        #[ink(message, selector = 0x9409BD56)]
+        pub fn reservar_stock(&mut self, id_producto: u64, cantidad: u32) -> Result<ProductoTienda, ErrorTienda> {
+            self._reservar_stock(self.env().caller(), id_producto, cantidad)
+        }
+
+        /// Método interno que descuenta stock de un producto.
+        fn _reservar_stock(
+            &mut self,
+            caller: AccountId,
+            id_producto: u64,
+            cantidad: u32,
+        ) -> Result<ProductoTienda, ErrorTienda> {
+            if caller != self.marketplace {
+                return Err(ErrorTienda::NoEresMarketplace);
+            }
+
+            let producto = self
+                .productos
+                .get_mut(id_producto as usize)
+                .ok_or(ErrorTienda::ProductoNoExistente)?;
+
+            producto.stock = producto
+                .stock
+                .checked_sub(cantidad as u64)
+                .ok_or(ErrorTienda::ProductoSinStock)?;
+
+            Ok(producto.clone())
+        }
+
+        /// Devuelve stock de un producto al cancelarse una orden.
+        ///
+        /// Solo el marketplace puede realizar esta acción.
+        #[ink(message, selector = 0x1413A444)]
+        pub fn restaurar_stock(&mut self, id_producto: u64, cantidad: u32) -> Result<ProductoTienda, ErrorTienda> {
+            self._restaurar_stock(self.env().caller(), id_producto, cantidad)
+        }
+
+        /// Método interno que repone stock de un producto.
+        fn _restaurar_stock(
+            &mut self,
+            caller: AccountId,
+            id_producto: u64,
+            cantidad: u32,
+        ) -> Result<ProductoTienda, ErrorTienda> {
+            if caller != self.marketplace {
+                return Err(ErrorTienda::NoEresMarketplace);
+            }
+
+            let producto = self
+                .productos
+                .get_mut(id_producto as usize)
+                .ok_or(ErrorTienda::ProductoNoExistente)?;
+
+            producto.stock = producto
+                .stock
+                .checked_add(cantidad as u64)
+                .ok_or(ErrorTienda::OverflowStock)?;
+
+            Ok(producto.clone())
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        /// Verifica que solo el propietario pueda agregar productos.
+        #[ink::test]
+        fn tests_agregar_producto() {
+            let propietario = AccountId::from([0xAA; 32]);
+            let marketplace = AccountId::from([0x01; 32]);
+            let mut tienda = Tienda::new(propietario, marketplace);
+
+            let result = tienda._agregar_producto(propietario, "Item".into(), "Desc".into(), Categoria::Ropa, 100, 10);
+            assert!(result.is_ok());
+            assert_eq!(tienda.get_productos().len(), 1);
+
+            let result = tienda._agregar_producto(marketplace, "Item".into(), "Desc".into(), Categoria::Ropa, 100, 10);
+            assert_eq!(result, Err(ErrorTienda::NoEresPropietario));
+        }
+
+        /// Verifica que solo el marketplace pueda reservar y restaurar stock.
+        #[ink::test]
+        fn tests_reservar_y_restaurar_stock() {
+            let propietario = AccountId::from([0xAA; 32]);
+            let marketplace = AccountId::from([0x01; 32]);
+            let mut tienda = Tienda::new(propietario, marketplace);
+            let _ = tienda._agregar_producto(propietario, "Item".into(), "Desc".into(), Categoria::Ropa, 100, 10);
+
+            assert_eq!(tienda._reservar_stock(propietario, 0, 3), Err(ErrorTienda::NoEresMarketplace));
+            assert_eq!(tienda._reservar_stock(marketplace, 0, 3).map(|p| p.stock), Ok(7));
+            assert_eq!(tienda._reservar_stock(marketplace, 0, 8), Err(ErrorTienda::ProductoSinStock));
+            assert_eq!(tienda._restaurar_stock(marketplace, 0, 3).map(|p| p.stock), Ok(10));
+            assert_eq!(tienda._reservar_stock(marketplace, 1, 1), Err(ErrorTienda::ProductoNoExistente));
+        }
+    }
+}